@@ -2,14 +2,19 @@
 
 // --- IMPORTS ---
 use chrono::Utc;
+use flate2::read::ZlibDecoder;
 use serde::{Deserialize, Serialize};
 use quick_xml::de::from_str;
 use quick_xml::se::to_string;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use quick_xml::Writer;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::env;
 use std::fs;
+use std::io::Read as IoRead;
+use std::io::Write as IoWrite;
 use std::path::{Path, PathBuf};
 use std::thread;
 use std::time::Duration;
@@ -79,6 +84,35 @@ struct ModInstallInfo {
     name: String,
     // The path to the new version of the mod in a temporary "staging" area
     temp_path: String,
+    // Populated from the mod's manifest.json, if it shipped one
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+}
+
+// Optional per-mod manifest, following the Thunderstore `name`/`version_number` convention
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct ThunderstoreManifest {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    version_number: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
+}
+
+// What's persisted to disk for an installed mod so later sessions can check for updates
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+struct InstalledModRecord {
+    // The manifest's own `name`, which is what the catalog keys on — not necessarily
+    // the same as the mod's folder name (the HashMap key this record is stored under)
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    author: Option<String>,
 }
 
 // New struct to report the complete results of the archive analysis to JavaScript
@@ -92,6 +126,35 @@ struct InstallationAnalysis {
     messy_archive_path: Option<String>,
 }
 
+// A single internal game file that more than one installed mod overlays
+#[derive(serde::Serialize)]
+struct FileConflict {
+    // Normalized internal PSARC path, e.g. "models/buildings/habbase/hab_core.scene.mbin"
+    path: String,
+    // Mod folder names that all pack this same path
+    mods: Vec<String>,
+    // The mod that currently wins at load time, per ModPriority in GCMODSETTINGS.MXML
+    winning_mod: String,
+}
+
+// Full result of scanning every installed .pak for overlapping internal paths
+#[derive(serde::Serialize)]
+struct ModConflictReport {
+    conflicts: Vec<FileConflict>,
+}
+
+// A single entry in the remote mod catalog's JSON index
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct CatalogEntry {
+    name: String,
+    author: String,
+    version: String,
+    description: String,
+    download_url: String,
+    #[serde(default)]
+    sha256: Option<String>,
+}
+
 // --- HELPER FUNCTIONS (Unchanged) ---
 fn get_state_file_path() -> PathBuf {
     let exe_path = env::current_exe().expect("Failed to find executable path");
@@ -99,6 +162,65 @@ fn get_state_file_path() -> PathBuf {
     exe_dir.join("window-state.json")
 }
 
+fn get_installed_mods_path() -> PathBuf {
+    let exe_path = env::current_exe().expect("Failed to find executable path");
+    let exe_dir = exe_path.parent().expect("Failed to get parent directory of executable");
+    exe_dir.join("installed-mods.json")
+}
+
+fn load_installed_mods() -> HashMap<String, InstalledModRecord> {
+    fs::read_to_string(get_installed_mods_path())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_installed_mods(records: &HashMap<String, InstalledModRecord>) {
+    if let Ok(json) = serde_json::to_string_pretty(records) {
+        let _ = fs::write(get_installed_mods_path(), json);
+    }
+}
+
+/// Reads the optional `manifest.json` shipped inside an installed mod folder.
+fn read_mod_manifest(mod_path: &Path) -> Option<ThunderstoreManifest> {
+    let manifest_content = fs::read_to_string(mod_path.join("manifest.json")).ok()?;
+    serde_json::from_str(&manifest_content).ok()
+}
+
+/// Reads `manifest.json` from an installed mod folder (if present) and records its
+/// name/version/author in `installed-mods.json` so `check_for_updates` can later
+/// compare against the catalog.
+fn persist_mod_manifest(mod_name: &str, final_mod_path: &Path) -> Option<ThunderstoreManifest> {
+    let manifest = read_mod_manifest(final_mod_path)?;
+    let mut installed = load_installed_mods();
+    installed.insert(
+        mod_name.to_string(),
+        InstalledModRecord {
+            name: manifest.name.clone(),
+            version: manifest.version_number.clone(),
+            author: manifest.author.clone(),
+        },
+    );
+    save_installed_mods(&installed);
+    Some(manifest)
+}
+
+/// Compares two `MAJOR.MINOR.PATCH` version strings numerically, per part. Missing or
+/// non-numeric parts are treated as 0 so partial version strings still compare sanely.
+fn compare_semver(a: &str, b: &str) -> std::cmp::Ordering {
+    let parse = |v: &str| -> Vec<u64> { v.split('.').map(|part| part.trim().parse::<u64>().unwrap_or(0)).collect() };
+    let (a_parts, b_parts) = (parse(a), parse(b));
+    for i in 0..a_parts.len().max(b_parts.len()) {
+        let a_part = a_parts.get(i).copied().unwrap_or(0);
+        let b_part = b_parts.get(i).copied().unwrap_or(0);
+        match a_part.cmp(&b_part) {
+            std::cmp::Ordering::Equal => continue,
+            other => return other,
+        }
+    }
+    std::cmp::Ordering::Equal
+}
+
 fn find_game_path() -> Option<PathBuf> {
     if cfg!(not(windows)) {
         return None;
@@ -148,7 +270,9 @@ fn find_steam_path() -> Option<PathBuf> {
     None
 }
 
-/// Extracts a zip or rar archive to a new temporary directory inside the mods folder.
+/// Extracts an archive to a new temporary directory inside the mods folder. Zip and RAR
+/// use their dedicated crates as fast paths; every other format (7z, tar.gz, tar.zst, ...)
+/// falls through to libarchive via compress-tools.
 fn extract_archive_to_temp(archive_path: &Path, mods_path: &Path) -> Result<PathBuf, String> {
     let temp_extract_path = mods_path.join(format!("temp_extract_{}", Utc::now().timestamp_millis()));
     fs::create_dir_all(&temp_extract_path).map_err(|e| e.to_string())?;
@@ -166,11 +290,209 @@ fn extract_archive_to_temp(archive_path: &Path, mods_path: &Path) -> Result<Path
                 archive = header.extract_to(&temp_extract_path).map_err(|e| format!("Failed to extract from RAR: {:?}", e))?;
             }
         }
-        _ => return Err(format!("Unsupported file type: .{}", extension)),
+        // Everything else (7z, tar.gz, tar.zst, ...) goes through libarchive via compress-tools,
+        // which handles any format it supports without us needing a branch per extension.
+        _ => {
+            let mut source = fs::File::open(archive_path).map_err(|e| format!("Failed to open archive: {}", e))?;
+            compress_tools::uncompress_archive(&mut source, &temp_extract_path, compress_tools::Ownership::Preserve)
+                .map_err(|e| format!("Failed to extract .{} archive: {}", extension, e))?;
+        }
     }
     Ok(temp_extract_path)
 }
 
+// Size of the fixed PSARC header: magic, version, compression tag, the four
+// TOC sizing fields below, and the archive flags (eight 4-byte fields).
+const PSARC_HEADER_LEN: usize = 32;
+// Size of one TOC entry: 16-byte MD5 of the path, 4-byte first zBlock index,
+// 5-byte original size, 5-byte offset (all big-endian).
+const PSARC_TOC_ENTRY_LEN: usize = 30;
+
+/// Reads a PSARC's table of contents and returns the normalized internal path of
+/// every packed entry. PSARC never stores paths in plaintext: the path of every
+/// entry is instead the zlib-compressed line list held in TOC entry 0 (the
+/// "manifest" entry), one path per line in the same order as TOC entries 1..N.
+/// This parses the header and TOC just far enough to locate and inflate that one
+/// entry; no other asset data is ever read.
+fn read_psarc_manifest(pak_path: &Path) -> Result<Vec<String>, String> {
+    let err = |e: std::io::Error| format!("Failed to read {}: {}", pak_path.display(), e);
+    let mut file = fs::File::open(pak_path).map_err(err)?;
+
+    let mut header = [0u8; PSARC_HEADER_LEN];
+    file.read_exact(&mut header).map_err(err)?;
+    if &header[0..4] != b"PSAR" {
+        return Err(format!("{} is not a valid PSARC archive", pak_path.display()));
+    }
+    let compression = &header[8..12];
+    if compression != b"zlib" {
+        return Err(format!(
+            "{} uses unsupported PSARC compression {:?}",
+            pak_path.display(),
+            String::from_utf8_lossy(compression)
+        ));
+    }
+    let total_toc_size = u32::from_be_bytes(header[12..16].try_into().unwrap()) as usize;
+    let toc_entry_size = u32::from_be_bytes(header[16..20].try_into().unwrap()) as usize;
+    let num_files = u32::from_be_bytes(header[20..24].try_into().unwrap()) as usize;
+    let block_size = u32::from_be_bytes(header[24..28].try_into().unwrap()) as usize;
+    if toc_entry_size != PSARC_TOC_ENTRY_LEN || num_files == 0 || block_size == 0 || total_toc_size < PSARC_HEADER_LEN + num_files * toc_entry_size {
+        return Err(format!("{} has a malformed PSARC TOC header", pak_path.display()));
+    }
+
+    let mut toc = vec![0u8; total_toc_size - PSARC_HEADER_LEN];
+    file.read_exact(&mut toc).map_err(err)?;
+
+    let manifest_entry = &toc[0..toc_entry_size];
+    let first_block = u32::from_be_bytes(manifest_entry[16..20].try_into().unwrap()) as usize;
+    let original_size = read_uint40_be(&manifest_entry[20..25]);
+
+    // The zBlock length table follows the TOC entries; each slot's width depends on
+    // how many bytes are needed to represent a value up to `block_size`.
+    let block_len_width = if block_size > 0xFF_FFFF { 4 } else if block_size > 0xFFFF { 3 } else { 2 };
+    let zblock_lengths: Vec<u32> = toc[num_files * toc_entry_size..]
+        .chunks(block_len_width)
+        .map(|chunk| {
+            let mut buf = [0u8; 4];
+            buf[4 - chunk.len()..].copy_from_slice(chunk);
+            u32::from_be_bytes(buf)
+        })
+        .collect();
+
+    // Data blocks are stored back-to-back, in TOC order, starting right after the
+    // TOC; the manifest is always entry 0, so its blocks are the first bytes read.
+    // Each zBlock is its OWN independent zlib stream (not one stream split across
+    // blocks), so every block must be inflated on its own and the results joined;
+    // feeding the concatenated compressed bytes through a single decoder would stop
+    // at the first stream's end and silently truncate the rest of the manifest.
+    let mut manifest_text = String::new();
+    let mut remaining = original_size;
+    let mut block_index = first_block;
+    while remaining > 0 {
+        // A declared length of 0 means "exactly block_size bytes, stored verbatim".
+        let declared = *zblock_lengths
+            .get(block_index)
+            .ok_or_else(|| format!("{} manifest references an out-of-range zBlock", pak_path.display()))? as usize;
+        let block_span = (block_size as u64).min(remaining) as usize;
+        let chunk_len = if declared == 0 { block_span } else { declared };
+        let mut chunk = vec![0u8; chunk_len];
+        file.read_exact(&mut chunk).map_err(err)?;
+
+        if declared == 0 {
+            // Verbatim block: copy the bytes straight through, nothing to inflate.
+            manifest_text.push_str(&String::from_utf8_lossy(&chunk));
+        } else {
+            ZlibDecoder::new(&chunk[..])
+                .read_to_string(&mut manifest_text)
+                .map_err(|e| format!("Failed to inflate PSARC manifest block in {}: {}", pak_path.display(), e))?;
+        }
+
+        remaining -= block_span as u64;
+        block_index += 1;
+    }
+
+    Ok(manifest_text
+        .lines()
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.replace('\\', "/").to_lowercase())
+        .collect())
+}
+
+/// Reads a big-endian 40-bit (5-byte) unsigned integer, PSARC's size/offset width.
+fn read_uint40_be(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64)
+}
+
+/// Reads the load order (`ModPriority`) for every mod currently listed in
+/// GCMODSETTINGS.MXML. Lower values load first; the highest ModPriority among a
+/// set of conflicting mods loads last and wins. Mods with no entry yet are absent
+/// from the map and rank below every listed mod (they lose every conflict).
+fn read_mod_priorities(settings_file_path: &Path) -> HashMap<String, i64> {
+    let mut priorities = HashMap::new();
+    let Ok(xml_content) = fs::read_to_string(settings_file_path) else {
+        return priorities;
+    };
+    let Ok(root) = from_str::<SettingsData>(&xml_content) else {
+        return priorities;
+    };
+
+    for prop in root.properties.iter() {
+        if prop.name != "Data" {
+            continue;
+        }
+        for mod_entry in prop.mods.iter() {
+            let name = mod_entry.properties.iter().find(|p| p.name == "Name").and_then(|p| p.value.clone());
+            let priority = mod_entry.properties.iter().find(|p| p.name == "ModPriority").and_then(|p| p.value.as_ref()).and_then(|v| v.parse::<i64>().ok());
+            if let (Some(name), Some(priority)) = (name, priority) {
+                priorities.insert(name, priority);
+            }
+        }
+    }
+    priorities
+}
+
+#[tauri::command]
+fn analyze_mod_conflicts() -> Result<ModConflictReport, String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find the game installation path.".to_string())?;
+    let mods_path = game_path.join("GAMEDATA").join("MODS");
+    let settings_file_path = game_path.join("Binaries").join("SETTINGS").join("GCMODSETTINGS.MXML");
+    let priorities = read_mod_priorities(&settings_file_path);
+
+    let mod_folders: Vec<_> = fs::read_dir(&mods_path)
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .collect();
+
+    // Map each normalized internal file path to every mod folder that packs it
+    let mut path_owners: HashMap<String, Vec<String>> = HashMap::new();
+
+    for mod_folder in mod_folders {
+        let mod_name = mod_folder.file_name().to_string_lossy().into_owned();
+        let pak_entries = fs::read_dir(mod_folder.path())
+            .map_err(|e| e.to_string())?
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().extension().and_then(std::ffi::OsStr::to_str).map_or(false, |ext| ext.eq_ignore_ascii_case("pak")));
+
+        for pak_entry in pak_entries {
+            // A single corrupt/non-PSARC .pak shouldn't abort the whole scan; skip and
+            // keep going so the rest of the mod list still gets checked for conflicts.
+            let internal_paths = match read_psarc_manifest(&pak_entry.path()) {
+                Ok(paths) => paths,
+                Err(e) => {
+                    eprintln!("Skipping {}: {}", pak_entry.path().display(), e);
+                    continue;
+                }
+            };
+            for internal_path in internal_paths {
+                // Several .pak files in one mod folder may pack the same path; list the mod once
+                let owners = path_owners.entry(internal_path).or_default();
+                if !owners.contains(&mod_name) {
+                    owners.push(mod_name.clone());
+                }
+            }
+        }
+    }
+
+    let mut conflicts: Vec<FileConflict> = path_owners
+        .into_iter()
+        .filter(|(_, mods)| mods.len() > 1)
+        .map(|(path, mods)| {
+            // Higher ModPriority loads later and therefore wins; unregistered mods rank lowest
+            let winning_mod = mods
+                .iter()
+                .max_by_key(|mod_name| priorities.get(*mod_name).copied().unwrap_or(i64::MIN))
+                .cloned()
+                .unwrap_or_default();
+            FileConflict { path, mods, winning_mod }
+        })
+        .collect();
+
+    conflicts.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(ModConflictReport { conflicts })
+}
+
 #[tauri::command]
 fn install_mod_from_archive(archive_path_str: String) -> Result<InstallationAnalysis, String> {
     let archive_path = Path::new(&archive_path_str);
@@ -181,14 +503,24 @@ fn install_mod_from_archive(archive_path_str: String) -> Result<InstallationAnal
     // 1. Extract archive to a temporary location for analysis
     let temp_extract_path = extract_archive_to_temp(archive_path, &mods_path)?;
 
-    // 2. Analyze the extracted contents for valid mod folders
+    // 2. Sort the extracted contents into the MODS folder, flagging conflicts
+    analyze_extracted_mods(temp_extract_path, &mods_path)
+}
+
+/// Sorts the folders found inside `temp_extract_path` into the final MODS directory,
+/// staging anything that collides with an already-installed mod so the caller can
+/// prompt for confirmation via `resolve_conflict`. Shared by every install path
+/// (drag-dropped archives and catalog downloads alike) so they funnel through the
+/// same conflict-staging behaviour.
+fn analyze_extracted_mods(temp_extract_path: PathBuf, mods_path: &Path) -> Result<InstallationAnalysis, String> {
+    // 1. Analyze the extracted contents for valid mod folders
     let folder_entries: Vec<_> = fs::read_dir(&temp_extract_path)
         .map_err(|e| e.to_string())?
         .filter_map(Result::ok)
         .filter(|entry| entry.path().is_dir())
         .collect();
 
-    // 3. Handle "messy" archives (no containing folder)
+    // 2. Handle "messy" archives (no containing folder)
     if folder_entries.is_empty() {
         // Return the path to JS so it can prompt the user for a name
         return Ok(InstallationAnalysis {
@@ -198,9 +530,9 @@ fn install_mod_from_archive(archive_path_str: String) -> Result<InstallationAnal
         });
     }
 
-    // 4. Create a staging area for mods that have conflicts
+    // 3. Create a staging area for mods that have conflicts
     let staging_path = mods_path.join(format!("temp_staging_{}", Utc::now().timestamp_millis()));
-    
+
     let mut successes = Vec::new();
     let mut conflicts = Vec::new();
 
@@ -215,26 +547,33 @@ fn install_mod_from_archive(archive_path_str: String) -> Result<InstallationAnal
             }
             let staged_mod_path = staging_path.join(&mod_name);
             fs::rename(entry.path(), &staged_mod_path).map_err(|e| e.to_string())?;
+            // The staged copy isn't installed yet, so its manifest isn't persisted until resolve_conflict replaces it
+            let manifest = read_mod_manifest(&staged_mod_path);
             conflicts.push(ModInstallInfo {
                 name: mod_name,
                 temp_path: staged_mod_path.to_string_lossy().into_owned(),
+                version: manifest.as_ref().and_then(|m| m.version_number.clone()),
+                author: manifest.and_then(|m| m.author),
             });
         } else {
             // NEW MOD: Move directly to the final mods folder
             fs::rename(entry.path(), &final_dest_path).map_err(|e| e.to_string())?;
+            let manifest = persist_mod_manifest(&mod_name, &final_dest_path);
             successes.push(ModInstallInfo {
                 name: mod_name,
                 temp_path: final_dest_path.to_string_lossy().into_owned(),
+                version: manifest.as_ref().and_then(|m| m.version_number.clone()),
+                author: manifest.and_then(|m| m.author),
             });
         }
     }
 
     // Introduce a tiny delay to give the OS time to release the file handle
     thread::sleep(Duration::from_millis(100)); // 100ms
-    
-    // 5. Cleanup the initial extraction folder, which should now be empty
+
+    // 4. Cleanup the initial extraction folder, which should now be empty
     fs::remove_dir_all(&temp_extract_path).ok();
-    
+
     Ok(InstallationAnalysis {
         successes,
         conflicts,
@@ -256,6 +595,7 @@ fn resolve_conflict(mod_name: String, temp_mod_path_str: String, replace: bool)
             fs::remove_dir_all(&final_mod_path).map_err(|e| format!("Failed to remove old mod: {}", e))?;
         }
         fs::rename(&temp_mod_path, &final_mod_path).map_err(|e| format!("Failed to move new mod into place: {}", e))?;
+        persist_mod_manifest(&mod_name, &final_mod_path);
     } else {
         // User cancelled: just delete the temporary folder for this new mod
         fs::remove_dir_all(&temp_mod_path).map_err(|e| format!("Failed to cleanup temp mod folder: {}", e))?;
@@ -271,6 +611,93 @@ fn resolve_conflict(mod_name: String, temp_mod_path_str: String, replace: bool)
     Ok(())
 }
 
+// An installed mod for which the catalog lists a newer `version`
+#[derive(serde::Serialize)]
+struct ModUpdate {
+    name: String,
+    installed_version: String,
+    available: CatalogEntry,
+}
+
+#[tauri::command]
+fn check_for_updates(catalog_url: String) -> Result<Vec<ModUpdate>, String> {
+    let installed = load_installed_mods();
+    let catalog = fetch_mod_catalog(catalog_url)?;
+
+    let mut updates = Vec::new();
+    for (mod_name, record) in installed.iter() {
+        let Some(installed_version) = &record.version else { continue };
+        // The catalog keys on the manifest's own name, which may differ from the
+        // folder name this record is stored under; fall back to the folder name when
+        // the manifest had no `name`. Mods with no manifest.json have no record at all.
+        let catalog_key = record.name.as_deref().unwrap_or(mod_name);
+        if let Some(catalog_entry) = catalog.iter().find(|entry| entry.name.eq_ignore_ascii_case(catalog_key)) {
+            if compare_semver(&catalog_entry.version, installed_version) == std::cmp::Ordering::Greater {
+                updates.push(ModUpdate {
+                    name: mod_name.clone(),
+                    installed_version: installed_version.clone(),
+                    available: catalog_entry.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(updates)
+}
+
+#[tauri::command]
+fn fetch_mod_catalog(repo_url: String) -> Result<Vec<CatalogEntry>, String> {
+    let response = reqwest::blocking::get(&repo_url)
+        .map_err(|e| format!("Failed to reach catalog at '{}': {}", repo_url, e))?
+        .error_for_status()
+        .map_err(|e| format!("Catalog at '{}' returned an error: {}", repo_url, e))?;
+    let entries: Vec<CatalogEntry> = response.json().map_err(|e| format!("Failed to parse catalog index: {}", e))?;
+    Ok(entries)
+}
+
+#[tauri::command]
+fn download_and_install_mod(entry: CatalogEntry) -> Result<InstallationAnalysis, String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find the game installation path.".to_string())?;
+    let mods_path = game_path.join("GAMEDATA").join("MODS");
+    fs::create_dir_all(&mods_path).map_err(|e| e.to_string())?;
+
+    // 1. Stream the archive down to a temp file, hashing as each chunk arrives so we
+    // never hold the whole archive in memory at once
+    let mut response = reqwest::blocking::get(&entry.download_url)
+        .map_err(|e| format!("Failed to download '{}': {}", entry.name, e))?
+        .error_for_status()
+        .map_err(|e| format!("Download for '{}' returned an error: {}", entry.name, e))?;
+    let extension = Path::new(&entry.download_url).extension().and_then(std::ffi::OsStr::to_str).unwrap_or("zip");
+    let download_path = mods_path.join(format!("temp_download_{}.{}", Utc::now().timestamp_millis(), extension));
+
+    let mut download_file = fs::File::create(&download_path).map_err(|e| format!("Failed to create temp file for '{}': {}", entry.name, e))?;
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let read = response.read(&mut buf).map_err(|e| format!("Failed to read download stream for '{}': {}", entry.name, e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+        download_file.write_all(&buf[..read]).map_err(|e| format!("Failed to save download for '{}': {}", entry.name, e))?;
+    }
+    drop(download_file);
+
+    // 2. Verify the checksum, if the catalog supplied one
+    if let Some(expected_sha256) = &entry.sha256 {
+        let actual_sha256 = hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect::<String>();
+        if !actual_sha256.eq_ignore_ascii_case(expected_sha256) {
+            fs::remove_file(&download_path).ok();
+            return Err(format!("Checksum mismatch for '{}': expected {}, got {}", entry.name, expected_sha256, actual_sha256));
+        }
+    }
+
+    // 3. Feed the verified archive through the same staging/conflict-analysis path as a local install
+    let temp_extract_path = extract_archive_to_temp(&download_path, &mods_path)?;
+    let result = analyze_extracted_mods(temp_extract_path, &mods_path);
+    fs::remove_file(&download_path).ok();
+    result
+}
 
 // --- OTHER TAURI COMMANDS (Unchanged) ---
 #[tauri::command]
@@ -374,6 +801,13 @@ fn delete_mod(mod_name: String) -> Result<String, String> {
             .map_err(|e| format!("Failed to delete mod folder for '{}': {}", mod_name, e))?;
     }
 
+    // 2b. Drop its installed-mods.json record too, so check_for_updates stops
+    // comparing a catalog entry against a mod that no longer exists
+    let mut installed = load_installed_mods();
+    if installed.remove(&mod_name).is_some() {
+        save_installed_mods(&installed);
+    }
+
     // 3. Read and Deserialize
     let xml_content = fs::read_to_string(&settings_file_path)
         .map_err(|e| format!("Failed to read GCMODSETTINGS.MXML: {}", e))?;
@@ -403,7 +837,15 @@ fn delete_mod(mod_name: String) -> Result<String, String> {
     }
 
     // 5. Serialize and Re-format
-    let unformatted_xml = to_string(&root).map_err(|e| e.to_string())?;
+    // 6. Return the perfect content to JavaScript, DO NOT SAVE.
+    serialize_settings_data(&root)
+}
+
+/// Serializes and re-indents a `SettingsData` tree back into the GCMODSETTINGS.MXML
+/// text format. Shared by every command that mutates the mod list/load order, since
+/// none of them save directly — the formatted string is handed back to JS to write.
+fn serialize_settings_data(root: &SettingsData) -> Result<String, String> {
+    let unformatted_xml = to_string(root).map_err(|e| e.to_string())?;
     let mut reader = Reader::from_str(&unformatted_xml);
     reader.trim_text(true);
     let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
@@ -416,10 +858,284 @@ fn delete_mod(mod_name: String) -> Result<String, String> {
     }
     let buf = writer.into_inner();
     let xml_body = String::from_utf8(buf).map_err(|e| e.to_string())?;
-    let final_content = format!("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n{}", xml_body);
+    Ok(format!("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n{}", xml_body))
+}
 
-    // 6. Return the perfect content to JavaScript, DO NOT SAVE.
-    Ok(final_content)
+/// Builds a brand-new `Mod` entry for GCMODSETTINGS.MXML, used whenever `set_mod_enabled`
+/// or `apply_profile` needs to list a mod that isn't already in the file. The wrapping
+/// `<Property>`'s own `@name`/`@value` (`"Mod"` / `""`) are inferred from this file's own
+/// schema rather than copied from a captured real save — every entry this tool has ever
+/// read back out already had them, since they only ever get re-indexed, never rebuilt from
+/// scratch. UNVERIFIED against a real GCMODSETTINGS.MXML; if NMS expects something else on
+/// freshly-added entries, confirm and fix here (shared by both call sites).
+fn new_mod_entry(mod_name: &str, index: usize) -> ModEntry {
+    let index = index.to_string();
+    ModEntry {
+        entry_name: "Mod".to_string(),
+        entry_value: String::new(),
+        index: index.clone(),
+        properties: vec![
+            ModProperty { name: "Name".to_string(), value: Some(mod_name.to_string()) },
+            ModProperty { name: "ModPriority".to_string(), value: Some(index) },
+        ],
+    }
+}
+
+#[tauri::command]
+fn set_mod_order(ordered_names: Vec<String>) -> Result<String, String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find game installation path.".to_string())?;
+    let settings_file_path = game_path.join("Binaries").join("SETTINGS").join("GCMODSETTINGS.MXML");
+
+    let xml_content = fs::read_to_string(&settings_file_path)
+        .map_err(|e| format!("Failed to read GCMODSETTINGS.MXML: {}", e))?;
+    let mut root: SettingsData = from_str(&xml_content)
+        .map_err(|e| format!("Failed to parse GCMODSETTINGS.MXML: {}", e))?;
+
+    for prop in root.properties.iter_mut() {
+        if prop.name != "Data" {
+            continue;
+        }
+
+        // Re-sort the existing entries to match the caller's supplied order
+        let mut reordered = Vec::with_capacity(prop.mods.len());
+        for name in &ordered_names {
+            if let Some(pos) = prop.mods.iter().position(|entry| {
+                entry.properties.iter().find(|p| p.name == "Name").and_then(|p| p.value.as_ref()).map_or(false, |v| v.eq_ignore_ascii_case(name))
+            }) {
+                reordered.push(prop.mods.remove(pos));
+            }
+        }
+        // Any entry the caller didn't mention keeps its relative place at the end
+        reordered.append(&mut prop.mods);
+        prop.mods = reordered;
+
+        for (i, mod_entry) in prop.mods.iter_mut().enumerate() {
+            let new_index = i.to_string();
+            mod_entry.index = new_index.clone();
+            if let Some(priority_prop) = mod_entry.properties.iter_mut().find(|p| p.name == "ModPriority") {
+                priority_prop.value = Some(new_index);
+            }
+        }
+        break;
+    }
+
+    serialize_settings_data(&root)
+}
+
+#[tauri::command]
+fn set_mod_enabled(mod_name: String, enabled: bool) -> Result<String, String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find game installation path.".to_string())?;
+    let settings_file_path = game_path.join("Binaries").join("SETTINGS").join("GCMODSETTINGS.MXML");
+    let mods_path = game_path.join("GAMEDATA").join("MODS");
+    let disabled_mods_path = game_path.join("GAMEDATA").join("MODS_DISABLED");
+    fs::create_dir_all(&disabled_mods_path).map_err(|e| e.to_string())?;
+
+    // 1. Read and deserialize the settings first: if this fails, bail out before
+    // touching the mod folder so the folder and settings never drift out of sync
+    let xml_content = fs::read_to_string(&settings_file_path)
+        .map_err(|e| format!("Failed to read GCMODSETTINGS.MXML: {}", e))?;
+    let mut root: SettingsData = from_str(&xml_content)
+        .map_err(|e| format!("Failed to parse GCMODSETTINGS.MXML: {}", e))?;
+
+    // 2. Move the mod folder between the active and disabled directories
+    if enabled {
+        let from = disabled_mods_path.join(&mod_name);
+        let to = mods_path.join(&mod_name);
+        if from.exists() {
+            fs::rename(from, to).map_err(|e| format!("Failed to re-enable mod folder for '{}': {}", mod_name, e))?;
+        }
+    } else {
+        let from = mods_path.join(&mod_name);
+        let to = disabled_mods_path.join(&mod_name);
+        if from.exists() {
+            fs::rename(from, to).map_err(|e| format!("Failed to disable mod folder for '{}': {}", mod_name, e))?;
+        }
+    }
+
+    // 3. Add or remove the mod's entry, then re-index everything that remains
+    for prop in root.properties.iter_mut() {
+        if prop.name != "Data" {
+            continue;
+        }
+
+        if enabled {
+            let already_listed = prop.mods.iter().any(|entry| {
+                entry.properties.iter().find(|p| p.name == "Name").and_then(|p| p.value.as_ref()).map_or(false, |v| v.eq_ignore_ascii_case(&mod_name))
+            });
+            if !already_listed {
+                let next_index = prop.mods.len();
+                prop.mods.push(new_mod_entry(&mod_name, next_index));
+            }
+        } else {
+            prop.mods.retain(|entry| {
+                if let Some(name_prop) = entry.properties.iter().find(|p| p.name == "Name") {
+                    if let Some(name_value) = &name_prop.value {
+                        !name_value.eq_ignore_ascii_case(&mod_name)
+                    } else { true }
+                } else { true }
+            });
+        }
+
+        for (i, mod_entry) in prop.mods.iter_mut().enumerate() {
+            let new_index = i.to_string();
+            mod_entry.index = new_index.clone();
+            if let Some(priority_prop) = mod_entry.properties.iter_mut().find(|p| p.name == "ModPriority") {
+                priority_prop.value = Some(new_index);
+            }
+        }
+        break;
+    }
+
+    serialize_settings_data(&root)
+}
+
+// A saved loadout: the exact set of mods that should be active, in load order
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ModProfile {
+    mods: Vec<String>,
+}
+
+fn get_profiles_dir() -> PathBuf {
+    let exe_path = env::current_exe().expect("Failed to find executable path");
+    let exe_dir = exe_path.parent().expect("Failed to get parent directory of executable");
+    exe_dir.join("profiles")
+}
+
+fn get_profile_path(name: &str) -> PathBuf {
+    get_profiles_dir().join(format!("{}.json", name))
+}
+
+#[tauri::command]
+fn save_profile(name: String) -> Result<(), String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find game installation path.".to_string())?;
+    let mods_path = game_path.join("GAMEDATA").join("MODS");
+    let settings_file_path = game_path.join("Binaries").join("SETTINGS").join("GCMODSETTINGS.MXML");
+
+    // 1. The currently active mod folders
+    let active_mods: Vec<String> = fs::read_dir(&mods_path)
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+
+    // 2. Their load order, per GCMODSETTINGS.MXML; anything not yet listed keeps its folder order at the end
+    let xml_content = fs::read_to_string(&settings_file_path)
+        .map_err(|e| format!("Failed to read GCMODSETTINGS.MXML: {}", e))?;
+    let root: SettingsData = from_str(&xml_content)
+        .map_err(|e| format!("Failed to parse GCMODSETTINGS.MXML: {}", e))?;
+
+    let mut ordered_names: Vec<String> = Vec::new();
+    for prop in root.properties.iter() {
+        if prop.name != "Data" {
+            continue;
+        }
+        for mod_entry in prop.mods.iter() {
+            if let Some(name_value) = mod_entry.properties.iter().find(|p| p.name == "Name").and_then(|p| p.value.clone()) {
+                if active_mods.iter().any(|m| m.eq_ignore_ascii_case(&name_value)) {
+                    ordered_names.push(name_value);
+                }
+            }
+        }
+        break;
+    }
+    for mod_name in &active_mods {
+        if !ordered_names.iter().any(|m| m.eq_ignore_ascii_case(mod_name)) {
+            ordered_names.push(mod_name.clone());
+        }
+    }
+
+    let profiles_dir = get_profiles_dir();
+    fs::create_dir_all(&profiles_dir).map_err(|e| e.to_string())?;
+    let profile = ModProfile { mods: ordered_names };
+    let json = serde_json::to_string_pretty(&profile).map_err(|e| e.to_string())?;
+    fs::write(get_profile_path(&name), json).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+fn list_profiles() -> Result<Vec<String>, String> {
+    let profiles_dir = get_profiles_dir();
+    if !profiles_dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut names: Vec<String> = fs::read_dir(&profiles_dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().and_then(std::ffi::OsStr::to_str) == Some("json"))
+        .filter_map(|entry| entry.path().file_stem().map(|s| s.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+#[tauri::command]
+fn apply_profile(name: String) -> Result<String, String> {
+    let game_path = find_game_path().ok_or_else(|| "Could not find game installation path.".to_string())?;
+    let mods_path = game_path.join("GAMEDATA").join("MODS");
+    let disabled_mods_path = game_path.join("GAMEDATA").join("MODS_DISABLED");
+    let settings_file_path = game_path.join("Binaries").join("SETTINGS").join("GCMODSETTINGS.MXML");
+    fs::create_dir_all(&mods_path).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&disabled_mods_path).map_err(|e| e.to_string())?;
+
+    let profile_json = fs::read_to_string(get_profile_path(&name))
+        .map_err(|e| format!("Failed to read profile '{}': {}", name, e))?;
+    let profile: ModProfile = serde_json::from_str(&profile_json).map_err(|e| format!("Failed to parse profile '{}': {}", name, e))?;
+
+    // 1. Disable any currently-active mod the profile doesn't want
+    let active_mods: Vec<String> = fs::read_dir(&mods_path)
+        .map_err(|e| e.to_string())?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    for mod_name in &active_mods {
+        if !profile.mods.iter().any(|m| m.eq_ignore_ascii_case(mod_name)) {
+            fs::rename(mods_path.join(mod_name), disabled_mods_path.join(mod_name))
+                .map_err(|e| format!("Failed to disable mod folder for '{}': {}", mod_name, e))?;
+        }
+    }
+
+    // 2. Re-enable any mod the profile wants that's currently sitting disabled
+    for mod_name in &profile.mods {
+        let disabled_path = disabled_mods_path.join(mod_name);
+        if disabled_path.exists() {
+            fs::rename(&disabled_path, mods_path.join(mod_name))
+                .map_err(|e| format!("Failed to re-enable mod folder for '{}': {}", mod_name, e))?;
+        }
+    }
+
+    // 3. Rebuild the Mod entries/ModPriority in GCMODSETTINGS.MXML to match the profile exactly
+    let xml_content = fs::read_to_string(&settings_file_path)
+        .map_err(|e| format!("Failed to read GCMODSETTINGS.MXML: {}", e))?;
+    let mut root: SettingsData = from_str(&xml_content)
+        .map_err(|e| format!("Failed to parse GCMODSETTINGS.MXML: {}", e))?;
+
+    for prop in root.properties.iter_mut() {
+        if prop.name != "Data" {
+            continue;
+        }
+
+        let mut rebuilt = Vec::with_capacity(profile.mods.len());
+        for (i, mod_name) in profile.mods.iter().enumerate() {
+            let index = i.to_string();
+            let existing = prop.mods.iter().position(|entry| {
+                entry.properties.iter().find(|p| p.name == "Name").and_then(|p| p.value.as_ref()).map_or(false, |v| v.eq_ignore_ascii_case(mod_name))
+            });
+            let mut entry = match existing {
+                Some(pos) => prop.mods.remove(pos),
+                None => new_mod_entry(mod_name, i),
+            };
+            entry.index = index.clone();
+            if let Some(priority_prop) = entry.properties.iter_mut().find(|p| p.name == "ModPriority") {
+                priority_prop.value = Some(index);
+            }
+            rebuilt.push(entry);
+        }
+        prop.mods = rebuilt;
+        break;
+    }
+
+    serialize_settings_data(&root)
 }
 
 // --- MAIN FUNCTION ---
@@ -504,13 +1220,109 @@ fn main() {
             toggle_maximize_window,
             close_window,
             delete_settings_file,
+            analyze_mod_conflicts,
             install_mod_from_archive,
             resolve_conflict,
+            fetch_mod_catalog,
+            download_and_install_mod,
+            check_for_updates,
             finalize_mod_installation,
             cleanup_temp_folder,
             resize_window,
-            delete_mod
+            delete_mod,
+            set_mod_order,
+            set_mod_enabled,
+            save_profile,
+            list_profiles,
+            apply_profile
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write as _;
+
+    // Builds a minimal single-entry PSARC (the TOC's entry 0, the manifest, is the
+    // only entry) whose manifest spans two independent zBlocks: one zlib-compressed,
+    // one stored verbatim. Exercises the per-block inflate path in
+    // `read_psarc_manifest`, including the case that used to get truncated or error
+    // out when the two blocks were handled as a single zlib stream.
+    fn build_test_psarc(block0: &[u8], block1: &[u8]) -> Vec<u8> {
+        const TOC_ENTRY_SIZE: u32 = PSARC_TOC_ENTRY_LEN as u32;
+        let block_size = block0.len() as u32;
+        assert_eq!(block1.len() as u32, block_size, "test blocks must match block_size");
+
+        let mut compressed_block0 = Vec::new();
+        let mut encoder = ZlibEncoder::new(&mut compressed_block0, Compression::default());
+        encoder.write_all(block0).unwrap();
+        encoder.finish().unwrap();
+
+        let zblock_table: Vec<u8> = [
+            (compressed_block0.len() as u16).to_be_bytes(),
+            0u16.to_be_bytes(), // verbatim block
+        ]
+        .concat();
+
+        let total_toc_size = PSARC_HEADER_LEN as u32 + TOC_ENTRY_SIZE + zblock_table.len() as u32;
+        let original_size = (block0.len() + block1.len()) as u64;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"PSAR"); // magic
+        bytes.extend_from_slice(&[0u8; 4]); // version (unused)
+        bytes.extend_from_slice(b"zlib"); // compression
+        bytes.extend_from_slice(&total_toc_size.to_be_bytes());
+        bytes.extend_from_slice(&TOC_ENTRY_SIZE.to_be_bytes());
+        bytes.extend_from_slice(&1u32.to_be_bytes()); // num_files
+        bytes.extend_from_slice(&block_size.to_be_bytes());
+        bytes.extend_from_slice(&[0u8; 4]); // archive_flags
+
+        bytes.extend_from_slice(&[0u8; 16]); // manifest MD5 (unused)
+        bytes.extend_from_slice(&0u32.to_be_bytes()); // first_block
+        bytes.extend_from_slice(&original_size.to_be_bytes()[3..8]); // 5-byte original size
+        bytes.extend_from_slice(&0u64.to_be_bytes()[3..8]); // 5-byte offset (unused)
+
+        bytes.extend_from_slice(&zblock_table);
+        bytes.extend_from_slice(&compressed_block0);
+        bytes.extend_from_slice(block1);
+        bytes
+    }
+
+    #[test]
+    fn read_psarc_manifest_joins_independently_compressed_and_verbatim_blocks() {
+        let archive = build_test_psarc(b"eg/a", b"eg/b");
+        let path = std::env::temp_dir().join(format!("nms-mod-manager-test-{}.pak", std::process::id()));
+        fs::write(&path, &archive).unwrap();
+
+        let paths = read_psarc_manifest(&path).unwrap();
+
+        fs::remove_file(&path).ok();
+        assert_eq!(paths, vec!["eg/aeg/b".to_string()]);
+    }
+
+    #[test]
+    fn compare_semver_compares_numerically_not_lexically() {
+        use std::cmp::Ordering;
+        // "1.10.0" > "1.9.0" numerically, even though "1.10.0" < "1.9.0" as strings
+        assert_eq!(compare_semver("1.10.0", "1.9.0"), Ordering::Greater);
+        assert_eq!(compare_semver("1.9.0", "1.10.0"), Ordering::Less);
+    }
+
+    #[test]
+    fn compare_semver_treats_missing_parts_as_zero() {
+        use std::cmp::Ordering;
+        assert_eq!(compare_semver("1.2", "1.2.0"), Ordering::Equal);
+        assert_eq!(compare_semver("1.2.1", "1.2"), Ordering::Greater);
+    }
+
+    #[test]
+    fn compare_semver_treats_non_numeric_parts_as_zero() {
+        use std::cmp::Ordering;
+        assert_eq!(compare_semver("bad", "0.0.0"), Ordering::Equal);
+        assert_eq!(compare_semver("1.0.0", "bad"), Ordering::Greater);
+    }
 }
\ No newline at end of file